@@ -0,0 +1,75 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{packet, rtt::RttEstimate};
+
+/// Outcome of a slow-start algorithm processing an ack.
+///
+/// The congestion controller owns `cwnd`/`ssthresh` and is the sole mutator;
+/// the slow-start algorithm only reports what it wants done via this struct and
+/// the controller applies it in [`SlowStartResult::apply`]. `cwnd_increase` is
+/// added unconditionally; `exit_slow_start` signals the transition to
+/// congestion avoidance. `set_cwnd`/`set_ssthresh` let an algorithm *override*
+/// the window on exit (used by HyStart++'s FreeBSD CSS-exit modes) rather than
+/// keeping the inflated `cwnd`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SlowStartResult {
+    /// Bytes to add to `cwnd` for this ack.
+    pub cwnd_increase: usize,
+    /// Whether slow start should be left for congestion avoidance.
+    pub exit_slow_start: bool,
+    /// If set, override `cwnd` with this value on slow-start exit.
+    pub set_cwnd: Option<usize>,
+    /// If set, override `ssthresh` with this value on slow-start exit.
+    pub set_ssthresh: Option<usize>,
+}
+
+impl SlowStartResult {
+    /// Apply this result to the controller's `(cwnd, ssthresh)` and return the
+    /// updated pair. On exit, `ssthresh` defaults to the (post-increase) `cwnd`
+    /// and is then overridden by `set_ssthresh`; `cwnd` is overridden by
+    /// `set_cwnd`. This is the single point at which a slow-start algorithm's
+    /// window decisions take effect, so HyStart never competes with the
+    /// controller's accounting.
+    #[must_use]
+    pub fn apply(&self, cwnd: usize, ssthresh: usize) -> (usize, usize) {
+        let mut cwnd = cwnd + self.cwnd_increase;
+        let mut ssthresh = ssthresh;
+        if self.exit_slow_start {
+            ssthresh = cwnd;
+            if let Some(set_cwnd) = self.set_cwnd {
+                cwnd = set_cwnd;
+            }
+            if let Some(set_ssthresh) = self.set_ssthresh {
+                ssthresh = set_ssthresh;
+            }
+        }
+        (cwnd, ssthresh)
+    }
+}
+
+/// A slow-start algorithm, driven by the congestion controller.
+///
+/// Implementations observe sends and acks and report a [`SlowStartResult`]; the
+/// controller owns and mutates `cwnd`/`ssthresh`.
+pub trait SlowStart {
+    /// Called when a packet is sent, to let the algorithm track round bounds.
+    fn on_packet_sent(&mut self, sent_pn: packet::Number);
+
+    /// Called when packets are acked. `flight_at_send` is the bytes in flight at
+    /// the time the acked packet was *sent*, which HyStart++ uses for its
+    /// CSS-exit window reduction.
+    fn on_packets_acked(
+        &mut self,
+        curr_cwnd: usize,
+        ssthresh: usize,
+        new_acked: usize,
+        rtt_est: &RttEstimate,
+        max_datagram_size: usize,
+        largest_acked: packet::Number,
+        flight_at_send: usize,
+    ) -> SlowStartResult;
+}
@@ -0,0 +1,43 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    cc::classic_cc::{SlowStart, SlowStartResult},
+    packet,
+    rtt::RttEstimate,
+};
+
+/// Standard [RFC 5681] slow start: grow `cwnd` by the bytes newly acked until
+/// `cwnd >= ssthresh`. Used for the initial fall-back path and for any slow
+/// start after the first (once `ssthresh` is no longer the arbitrarily high
+/// initial value).
+///
+/// [RFC 5681]: https://datatracker.ietf.org/doc/html/rfc5681#section-3.1
+#[derive(Debug, Default, Clone, Copy, derive_more::Display)]
+#[display("ClassicSlowStart")]
+pub struct ClassicSlowStart {}
+
+impl SlowStart for ClassicSlowStart {
+    fn on_packet_sent(&mut self, _sent_pn: packet::Number) {}
+
+    fn on_packets_acked(
+        &mut self,
+        curr_cwnd: usize,
+        ssthresh: usize,
+        new_acked: usize,
+        _rtt_est: &RttEstimate,
+        _max_datagram_size: usize,
+        _largest_acked: packet::Number,
+        _flight_at_send: usize,
+    ) -> SlowStartResult {
+        SlowStartResult {
+            cwnd_increase: new_acked,
+            exit_slow_start: curr_cwnd + new_acked >= ssthresh,
+            set_cwnd: None,
+            set_ssthresh: None,
+        }
+    }
+}
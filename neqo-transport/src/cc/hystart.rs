@@ -6,6 +6,7 @@
 
 use std::{
     cmp::{max, min},
+    fmt::Debug,
     time::Duration,
 };
 
@@ -20,6 +21,97 @@ use crate::{
     rtt::RttEstimate,
 };
 
+/// Behavior when HyStart++ leaves CSS and returns to congestion avoidance.
+///
+/// Mode 1 is RFC 9406 behavior: keep the current (inflated) `cwnd` and set
+/// `ssthresh = cwnd`. Modes 2 and 3 mirror FreeBSD's experimental HyStart++
+/// and reduce the window using the flight-at-send (`fas`) samples to avoid
+/// the overshoot that mode 1 leaves in place.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum CssExitMode {
+    /// Keep `cwnd`, set `ssthresh = cwnd` (RFC 9406).
+    #[default]
+    #[display("1")]
+    Mode1,
+    /// Set `cwnd = lowrtt_fas`, `ssthresh = last_fas` (at least `lowrtt_fas`).
+    #[display("2")]
+    Mode2,
+    /// Set `cwnd = lowrtt_fas`, `ssthresh = (lowrtt_fas + last_fas) / 2` (at
+    /// least `lowrtt_fas`).
+    #[display("3")]
+    Mode3,
+}
+
+/// Why HyStart++ left CSS, reported to a [`HyStartObserver`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum CssExitReason {
+    /// The round's min RTT dropped back below the CSS baseline, so the RTT
+    /// increase was a false alarm and slow start resumes.
+    #[display("rtt recovered")]
+    RttRecovered,
+    /// CSS persisted for `css_rounds` rounds, so slow start is left for
+    /// congestion avoidance.
+    #[display("css round limit reached")]
+    RoundLimit,
+}
+
+/// Observer of HyStart++ decisions, analogous to FreeBSD's `newround`/`rttsample`
+/// cc callbacks. Lets integrators feed qlog or metrics without scraping the
+/// `qdebug!`/`qinfo!` log strings.
+pub trait HyStartObserver: Debug {
+    /// A new measurement round started. `last_round_min_rtt` is the min RTT of
+    /// the round that just ended (`Duration::MAX` if it collected no samples).
+    fn on_new_round(&mut self, round_count: usize, last_round_min_rtt: Duration);
+
+    /// An RTT sample was collected for the current round.
+    fn on_rtt_sample(&mut self, rtt: Duration, current_round_min_rtt: Duration, sample_count: usize);
+
+    /// CSS was entered. `threshold` is the RTT-increase threshold that was
+    /// crossed relative to the previous round's min RTT.
+    fn on_css_enter(&mut self, baseline_min_rtt: Duration, threshold: Duration);
+
+    /// CSS was left, for the given reason.
+    fn on_css_exit(&mut self, reason: CssExitReason);
+}
+
+/// Runtime-tunable HyStart++ parameters.
+///
+/// These mirror the sysctl tunables FreeBSD exposes for its HyStart++ module
+/// (minrtt/maxrtt thresh, n_rttsamples, css_growth_div, css_rounds, lowcwnd),
+/// letting operators dial CSS aggressiveness without recompiling. The defaults
+/// reproduce the RFC 9406 constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HyStartConfig {
+    /// Lower bound on the RTT increase that triggers CSS entry.
+    pub min_rtt_thresh: Duration,
+    /// Upper bound on the RTT increase that triggers CSS entry.
+    pub max_rtt_thresh: Duration,
+    /// Divisor applied to the last round's min RTT when deriving the threshold.
+    pub min_rtt_divisor: u32,
+    /// Number of RTT samples required per round before acting on the min RTT.
+    pub n_rtt_sample: usize,
+    /// Divisor that slows cwnd growth while in CSS.
+    pub css_growth_divisor: usize,
+    /// Number of CSS rounds after which slow start is left for CA.
+    pub css_rounds: usize,
+    /// Per-ack growth limit (in MSS) used when pacing is disabled.
+    pub non_paced_l: usize,
+}
+
+impl Default for HyStartConfig {
+    fn default() -> Self {
+        Self {
+            min_rtt_thresh: HyStart::MIN_RTT_THRESH,
+            max_rtt_thresh: HyStart::MAX_RTT_THRESH,
+            min_rtt_divisor: HyStart::MIN_RTT_DIVISOR,
+            n_rtt_sample: HyStart::N_RTT_SAMPLE,
+            css_growth_divisor: HyStart::CSS_GROWTH_DIVISOR,
+            css_rounds: HyStart::CSS_ROUNDS,
+            non_paced_l: HyStart::NON_PACED_L,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, derive_more::Display)]
 #[display("State [last_min: {last_round_min_rtt:?}, current_min: {current_round_min_rtt:?}, samples: {rtt_sample_count}, end: {window_end:?}, css: {css_baseline_min_rtt:?}")]
 pub struct State {
@@ -29,6 +121,17 @@ pub struct State {
     window_end: Option<packet::Number>,
     css_baseline_min_rtt: Duration,
     css_round_count: usize,
+    /// Number of measurement rounds started so far.
+    round_count: usize,
+    /// Flight-at-send of the lowest-RTT sample seen in the current round.
+    current_round_lowrtt_fas: usize,
+    /// Flight-at-send of the lowest-RTT sample seen in the previous round.
+    last_round_lowrtt_fas: usize,
+    /// Flight-at-send corresponding to the lowest RTT of the round before the
+    /// RTT increase that triggered CSS entry began.
+    lowrtt_fas: usize,
+    /// Flight-at-send of the ack that triggers CSS exit.
+    last_fas: usize,
 }
 
 impl State {
@@ -40,6 +143,11 @@ impl State {
             window_end: None,
             css_baseline_min_rtt: Duration::MAX,
             css_round_count: 0,
+            round_count: 0,
+            current_round_lowrtt_fas: 0,
+            last_round_lowrtt_fas: 0,
+            lowrtt_fas: 0,
+            last_fas: 0,
         }
     }
 }
@@ -48,6 +156,9 @@ impl State {
 #[display("HyStart++")]
 pub struct HyStart {
     limit: usize,
+    css_exit_mode: CssExitMode,
+    config: HyStartConfig,
+    observer: Option<Box<dyn HyStartObserver>>,
     current: State,
 }
 
@@ -66,43 +177,144 @@ impl HyStart {
 
     pub const NON_PACED_L: usize = 8;
 
-    pub const fn new(pacing: bool) -> Self {
+    pub const fn new(pacing: bool, config: HyStartConfig) -> Self {
         let limit = if pacing {
             usize::MAX
         } else {
-            Self::NON_PACED_L
+            config.non_paced_l
         };
         Self {
             limit,
+            css_exit_mode: CssExitMode::Mode1,
+            config,
+            observer: None,
             current: State::new(),
         }
     }
 
+    /// Select the window-reduction behavior applied when leaving CSS.
+    pub const fn set_css_exit_mode(&mut self, mode: CssExitMode) {
+        self.css_exit_mode = mode;
+    }
+
+    /// Register an observer for HyStart++ round, RTT-sample, and CSS events.
+    pub fn set_observer(&mut self, observer: Box<dyn HyStartObserver>) {
+        self.observer = Some(observer);
+    }
+
     pub fn in_css(&self) -> bool {
         self.current.css_baseline_min_rtt != Duration::MAX
     }
 
-    fn collect_rtt_sample(&mut self, rtt: Duration) {
-        self.current.current_round_min_rtt = min(self.current.current_round_min_rtt, rtt);
+    fn collect_rtt_sample(&mut self, rtt: Duration, flight_at_send: usize) {
+        if rtt < self.current.current_round_min_rtt {
+            self.current.current_round_min_rtt = rtt;
+            self.current.current_round_lowrtt_fas = flight_at_send;
+        }
         self.current.rtt_sample_count += 1;
+        if let Some(obs) = self.observer.as_deref_mut() {
+            obs.on_rtt_sample(
+                rtt,
+                self.current.current_round_min_rtt,
+                self.current.rtt_sample_count,
+            );
+        }
     }
 
     const fn maybe_exit_to_ca(&mut self) -> bool {
         self.current.css_round_count += 1;
-        self.current.css_round_count >= Self::CSS_ROUNDS
+        self.current.css_round_count >= self.config.css_rounds
     }
 
-    fn calc_cwnd_increase(&self, new_acked: usize, max_datagram_size: usize, css: bool) -> usize {
-        let mut cwnd_increase = min(self.limit.saturating_mul(max_datagram_size), new_acked);
-
-        if css {
-            cwnd_increase /= Self::CSS_GROWTH_DIVISOR;
+    /// Compute the `(set_cwnd, set_ssthresh)` overrides the controller should
+    /// apply when leaving CSS, per the configured [`CssExitMode`]. Mode 1 keeps
+    /// the caller's `cwnd` (both `None`, leaving `ssthresh = cwnd` to the
+    /// controller).
+    const fn css_exit_windows(&self) -> (Option<usize>, Option<usize>) {
+        let lowrtt_fas = self.current.lowrtt_fas;
+        let last_fas = self.current.last_fas;
+        match self.css_exit_mode {
+            CssExitMode::Mode1 => (None, None),
+            // `last_fas` can be below `lowrtt_fas` (e.g. after an
+            // RttRecovered-then-re-entered sequence), so clamp `ssthresh` to at
+            // least the new `cwnd` to preserve the `ssthresh >= curr_cwnd`
+            // invariant asserted on the next `on_packets_acked`.
+            CssExitMode::Mode2 => {
+                let ssthresh = if last_fas >= lowrtt_fas {
+                    last_fas
+                } else {
+                    lowrtt_fas
+                };
+                (Some(lowrtt_fas), Some(ssthresh))
+            }
+            CssExitMode::Mode3 => {
+                let midpoint = (lowrtt_fas + last_fas) / 2;
+                let ssthresh = if midpoint >= lowrtt_fas {
+                    midpoint
+                } else {
+                    lowrtt_fas
+                };
+                (Some(lowrtt_fas), Some(ssthresh))
+            }
         }
-        cwnd_increase
+    }
+
+    /// ABC-style per-ack increase during (non-CSS) slow start: the bytes newly
+    /// acked, capped at `L * MSS`.
+    fn slow_start_cwnd_increase(&self, new_acked: usize, max_datagram_size: usize) -> usize {
+        min(self.limit.saturating_mul(max_datagram_size), new_acked)
+    }
+
+    /// Per-ack cwnd increase while in CSS, following RFC 9406: the acked bytes
+    /// capped at the `L * MSS` per-ack slow-start limit, scaled down by
+    /// `css_growth_divisor`.
+    ///
+    /// CSS detection lives separately in [`Self::try_enter_css`]/[`Self::in_css`].
+    /// `on_packets_acked` only reports this amount in
+    /// [`SlowStartResult::cwnd_increase`]; the controller applies it and remains
+    /// the sole mutator of `cwnd`.
+    fn css_cwnd_increase(&self, new_acked: usize, max_datagram_size: usize) -> usize {
+        min(new_acked, self.limit.saturating_mul(max_datagram_size)) / self.config.css_growth_divisor
     }
 
     const fn enough_samples(&self) -> bool {
-        self.current.rtt_sample_count >= Self::N_RTT_SAMPLE
+        self.current.rtt_sample_count >= self.config.n_rtt_sample
+    }
+
+    /// Decide whether the current round's min RTT has risen far enough above the
+    /// previous round's to enter CSS, and perform the transition if so. Pure state
+    /// update; returns `true` when CSS was just entered. No effect once already
+    /// in CSS or before enough samples have been collected.
+    fn try_enter_css(&mut self) -> bool {
+        if self.in_css()
+            || !self.enough_samples()
+            || self.current.current_round_min_rtt == Duration::MAX
+            || self.current.last_round_min_rtt == Duration::MAX
+        {
+            return false;
+        }
+
+        let rtt_thresh = max(
+            self.config.min_rtt_thresh,
+            min(
+                self.current.last_round_min_rtt / self.config.min_rtt_divisor,
+                self.config.max_rtt_thresh,
+            ),
+        );
+
+        if self.current.current_round_min_rtt >= self.current.last_round_min_rtt + rtt_thresh {
+            self.current.css_baseline_min_rtt = self.current.current_round_min_rtt;
+            // The lowest RTT of the previous round marks where the increase
+            // began; remember its flight-at-send for a mode 2/3 exit.
+            self.current.lowrtt_fas = self.current.last_round_lowrtt_fas;
+            qinfo!("HyStart: try_enter_css -> entered CSS because cur_min={:?} >= last_min={:?} + thresh={rtt_thresh:?}",
+                   self.current.current_round_min_rtt, self.current.last_round_min_rtt);
+            if let Some(obs) = self.observer.as_deref_mut() {
+                obs.on_css_enter(self.current.css_baseline_min_rtt, rtt_thresh);
+            }
+            return true;
+        }
+        false
     }
 
     fn maybe_start_new_round(&mut self, sent_pn: packet::Number) {
@@ -111,9 +323,15 @@ impl HyStart {
         }
         self.current.window_end = Some(sent_pn);
         self.current.last_round_min_rtt = self.current.current_round_min_rtt;
+        self.current.last_round_lowrtt_fas = self.current.current_round_lowrtt_fas;
         self.current.current_round_min_rtt = Duration::MAX;
+        self.current.current_round_lowrtt_fas = 0;
         self.current.rtt_sample_count = 0;
+        self.current.round_count += 1;
         qdebug!("HyStart: maybe_start_new_round -> started new round");
+        if let Some(obs) = self.observer.as_deref_mut() {
+            obs.on_new_round(self.current.round_count, self.current.last_round_min_rtt);
+        }
     }
 
     #[cfg(test)]
@@ -154,6 +372,7 @@ impl SlowStart for HyStart {
         rtt_est: &RttEstimate,
         max_datagram_size: usize,
         largest_acked: packet::Number,
+        flight_at_send: usize,
     ) -> SlowStartResult {
         debug_assert!(
             ssthresh >= curr_cwnd,
@@ -176,10 +395,11 @@ impl SlowStart for HyStart {
                 rtt_est,
                 max_datagram_size,
                 largest_acked,
+                flight_at_send,
             );
         }
 
-        self.collect_rtt_sample(rtt_est.latest());
+        self.collect_rtt_sample(rtt_est.latest(), flight_at_send);
 
         qdebug!(
             "HyStart: on_packets_acked -> pn={largest_acked}, rtt={:?}, cur_min={:?}, last_min={:?}, samples={}, in_css={}, css_rounds={}, window_end={:?}",
@@ -205,29 +425,20 @@ impl SlowStart for HyStart {
 
             self.current.css_baseline_min_rtt = Duration::MAX;
             self.current.css_round_count = 0;
-        }
-        if !self.in_css()
-            && self.enough_samples()
-            && self.current.current_round_min_rtt != Duration::MAX
-            && self.current.last_round_min_rtt != Duration::MAX
-        {
-            let rtt_thresh = max(
-                Self::MIN_RTT_THRESH,
-                min(
-                    self.current.last_round_min_rtt / Self::MIN_RTT_DIVISOR,
-                    Self::MAX_RTT_THRESH,
-                ),
-            );
-
-            if self.current.current_round_min_rtt >= self.current.last_round_min_rtt + rtt_thresh {
-                self.current.css_baseline_min_rtt = self.current.current_round_min_rtt;
-                qinfo!("HyStart: on_packets_acked -> entered CSS because cur_min={:?} >= last_min={:?} + thresh={rtt_thresh:?}",
-                       self.current.current_round_min_rtt, self.current.last_round_min_rtt);
+            if let Some(obs) = self.observer.as_deref_mut() {
+                obs.on_css_exit(CssExitReason::RttRecovered);
             }
         }
+        self.try_enter_css();
 
         let mut exit_slow_start = false;
-        let cwnd_increase = self.calc_cwnd_increase(new_acked, max_datagram_size, self.in_css());
+        let mut set_cwnd = None;
+        let mut set_ssthresh = None;
+        let cwnd_increase = if self.in_css() {
+            self.css_cwnd_increase(new_acked, max_datagram_size)
+        } else {
+            self.slow_start_cwnd_increase(new_acked, max_datagram_size)
+        };
 
         // check for end of round
         if let Some(window_end) = self.current.window_end {
@@ -239,7 +450,18 @@ impl SlowStart for HyStart {
 
                 if self.in_css() {
                     exit_slow_start = self.maybe_exit_to_ca();
-                    qinfo!("HyStart: on_packets_acked -> exit={exit_slow_start} because css_rounds={} >= {}", self.current.css_round_count, Self::CSS_ROUNDS);
+                    qinfo!("HyStart: on_packets_acked -> exit={exit_slow_start} because css_rounds={} >= {}", self.current.css_round_count, self.config.css_rounds);
+
+                    if exit_slow_start {
+                        if let Some(obs) = self.observer.as_deref_mut() {
+                            obs.on_css_exit(CssExitReason::RoundLimit);
+                        }
+                        // Flight-at-send of the ack that triggers the exit.
+                        self.current.last_fas = flight_at_send;
+                        let (cwnd, ssthresh) = self.css_exit_windows();
+                        set_cwnd = cwnd;
+                        set_ssthresh = ssthresh;
+                    }
                 }
             }
         }
@@ -247,6 +469,202 @@ impl SlowStart for HyStart {
         SlowStartResult {
             cwnd_increase,
             exit_slow_start,
+            set_cwnd,
+            set_ssthresh,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, time::Duration};
+
+    use crate::{
+        cc::{
+            classic_cc::SlowStart,
+            hystart::{CssExitMode, CssExitReason, HyStart, HyStartConfig, HyStartObserver},
+        },
+        rtt::RttEstimate,
+    };
+
+    const MSS: usize = 1200;
+
+    fn exit_windows(mode: CssExitMode, lowrtt_fas: usize, last_fas: usize) -> (Option<usize>, Option<usize>) {
+        let mut hs = HyStart::new(true, HyStartConfig::default());
+        hs.set_css_exit_mode(mode);
+        hs.current.lowrtt_fas = lowrtt_fas;
+        hs.current.last_fas = last_fas;
+        hs.css_exit_windows()
+    }
+
+    #[test]
+    fn css_exit_mode_1_keeps_window() {
+        assert_eq!(exit_windows(CssExitMode::Mode1, 10_000, 20_000), (None, None));
+    }
+
+    #[test]
+    fn css_exit_mode_2_uses_fas() {
+        assert_eq!(
+            exit_windows(CssExitMode::Mode2, 10_000, 20_000),
+            (Some(10_000), Some(20_000))
+        );
+    }
+
+    #[test]
+    fn css_exit_mode_3_uses_midpoint() {
+        assert_eq!(
+            exit_windows(CssExitMode::Mode3, 10_000, 30_000),
+            (Some(10_000), Some(20_000))
+        );
+    }
+
+    #[test]
+    fn css_exit_clamps_ssthresh_when_last_below_lowrtt() {
+        // `last_fas < lowrtt_fas`: ssthresh must not drop below cwnd.
+        let (cwnd, ssthresh) = exit_windows(CssExitMode::Mode2, 20_000, 10_000);
+        assert_eq!((cwnd, ssthresh), (Some(20_000), Some(20_000)));
+        let (cwnd, ssthresh) = exit_windows(CssExitMode::Mode3, 30_000, 10_000);
+        assert_eq!((cwnd, ssthresh), (Some(30_000), Some(30_000)));
+    }
+
+    #[test]
+    fn config_n_rtt_sample_changes_entry_timing() {
+        // A jump well above the threshold only triggers CSS entry once the
+        // configured number of samples has been collected.
+        let enter_after = |n_rtt_sample| {
+            let mut hs = HyStart::new(
+                true,
+                HyStartConfig {
+                    n_rtt_sample,
+                    ..HyStartConfig::default()
+                },
+            );
+            hs.current.last_round_min_rtt = Duration::from_millis(20);
+            hs.collect_rtt_sample(Duration::from_millis(40), MSS);
+            let after_one = hs.try_enter_css();
+            hs.collect_rtt_sample(Duration::from_millis(40), MSS);
+            (after_one, hs.try_enter_css() || hs.in_css())
+        };
+        // Default requires 8 samples, so two are not enough.
+        assert_eq!(enter_after(HyStartConfig::default().n_rtt_sample), (false, false));
+        // Lowering the threshold to 2 lets the same two samples enter CSS.
+        assert_eq!(enter_after(2), (false, true));
+    }
+
+    #[test]
+    fn config_css_rounds_changes_exit_timing() {
+        let mut hs = HyStart::new(
+            true,
+            HyStartConfig {
+                css_rounds: 2,
+                ..HyStartConfig::default()
+            },
+        );
+        assert!(!hs.maybe_exit_to_ca());
+        assert!(hs.maybe_exit_to_ca());
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        new_rounds: Vec<(usize, Duration)>,
+        rtt_samples: Vec<(Duration, Duration, usize)>,
+        css_enters: Vec<(Duration, Duration)>,
+        css_exits: Vec<CssExitReason>,
+    }
+
+    #[derive(Debug, Clone)]
+    struct SharedRecorder(Rc<RefCell<Recorder>>);
+
+    impl HyStartObserver for SharedRecorder {
+        fn on_new_round(&mut self, round_count: usize, last_round_min_rtt: Duration) {
+            self.0.borrow_mut().new_rounds.push((round_count, last_round_min_rtt));
+        }
+
+        fn on_rtt_sample(&mut self, rtt: Duration, current_round_min_rtt: Duration, sample_count: usize) {
+            self.0
+                .borrow_mut()
+                .rtt_samples
+                .push((rtt, current_round_min_rtt, sample_count));
+        }
+
+        fn on_css_enter(&mut self, baseline_min_rtt: Duration, threshold: Duration) {
+            self.0.borrow_mut().css_enters.push((baseline_min_rtt, threshold));
+        }
+
+        fn on_css_exit(&mut self, reason: CssExitReason) {
+            self.0.borrow_mut().css_exits.push(reason);
+        }
+    }
+
+    fn with_recorder(hs: &mut HyStart) -> Rc<RefCell<Recorder>> {
+        let rec = Rc::new(RefCell::new(Recorder::default()));
+        hs.set_observer(Box::new(SharedRecorder(rec.clone())));
+        rec
+    }
+
+    #[test]
+    fn observer_sees_new_round_and_rtt_sample() {
+        let mut hs = HyStart::new(true, HyStartConfig::default());
+        let rec = with_recorder(&mut hs);
+        hs.maybe_start_new_round(7);
+        hs.collect_rtt_sample(Duration::from_millis(15), MSS);
+        let rec = rec.borrow();
+        assert_eq!(rec.new_rounds, vec![(1, Duration::MAX)]);
+        assert_eq!(
+            rec.rtt_samples,
+            vec![(Duration::from_millis(15), Duration::from_millis(15), 1)]
+        );
+    }
+
+    #[test]
+    fn observer_sees_css_enter() {
+        let mut hs = HyStart::new(
+            true,
+            HyStartConfig {
+                n_rtt_sample: 1,
+                ..HyStartConfig::default()
+            },
+        );
+        let rec = with_recorder(&mut hs);
+        hs.current.last_round_min_rtt = Duration::from_millis(20);
+        hs.collect_rtt_sample(Duration::from_millis(40), MSS);
+        assert!(hs.try_enter_css());
+        assert_eq!(rec.borrow().css_enters.len(), 1);
+    }
+
+    #[test]
+    fn observer_sees_css_exit_round_limit() {
+        let mut hs = HyStart::new(
+            true,
+            HyStartConfig {
+                css_rounds: 1,
+                ..HyStartConfig::default()
+            },
+        );
+        let rec = with_recorder(&mut hs);
+        hs.current.css_baseline_min_rtt = Duration::from_millis(10);
+        hs.current.window_end = Some(0);
+        let rtt = RttEstimate::default();
+        let res = hs.on_packets_acked(MSS, usize::MAX, MSS, &rtt, MSS, 10, MSS);
+        assert!(res.exit_slow_start);
+        assert_eq!(rec.borrow().css_exits, vec![CssExitReason::RoundLimit]);
+    }
+
+    #[test]
+    fn observer_sees_css_exit_rtt_recovered() {
+        let mut hs = HyStart::new(
+            true,
+            HyStartConfig {
+                n_rtt_sample: 1,
+                ..HyStartConfig::default()
+            },
+        );
+        let rec = with_recorder(&mut hs);
+        // Huge baseline so the first (smaller) sample looks like a recovery.
+        hs.current.css_baseline_min_rtt = Duration::from_secs(100);
+        let rtt = RttEstimate::default();
+        hs.on_packets_acked(MSS, usize::MAX, MSS, &rtt, MSS, 0, MSS);
+        assert!(!hs.in_css());
+        assert_eq!(rec.borrow().css_exits, vec![CssExitReason::RttRecovered]);
+    }
+}